@@ -0,0 +1,153 @@
+use crate::cli::erc20::{wrap_error, ParseError};
+use crate::eth_method::EthMethod;
+
+/// A JSON-RPC 2.0 batch of `EthMethod` calls, serialized as a single array
+/// so they can be sent in one HTTP round-trip instead of one request each.
+pub struct BatchRequest {
+    methods: Vec<EthMethod>,
+}
+
+impl BatchRequest {
+    pub fn new(methods: Vec<EthMethod>) -> Self {
+        Self { methods }
+    }
+
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+
+    /// Serializes the batch as a JSON-RPC 2.0 array, one object per method,
+    /// with sequential `id`s matching the method's position in the batch.
+    pub fn to_json(&self) -> serde_json::Value {
+        let requests = self
+            .methods
+            .iter()
+            .enumerate()
+            .map(|(id, method)| {
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "method": method.name(),
+                    "params": method.create_params(),
+                })
+            })
+            .collect();
+        serde_json::Value::Array(requests)
+    }
+
+    /// Sends the batch as a single HTTP POST to `url` and demultiplexes the
+    /// response back into one result per method.
+    pub fn send(&self, client: &reqwest::blocking::Client, url: &str) -> Result<BatchResponse, ParseError> {
+        let raw = client
+            .post(url)
+            .json(&self.to_json())
+            .send()
+            .map_err(wrap_error)?
+            .json::<serde_json::Value>()
+            .map_err(wrap_error)?;
+        BatchResponse::parse(self.len(), raw).map_err(|e| wrap_error(e.to_string()))
+    }
+}
+
+/// Builds a batch from `methods`, sends it to `url` in one HTTP round-trip,
+/// and returns the demultiplexed per-method results.
+pub fn send_batch(url: &str, methods: Vec<EthMethod>) -> Result<BatchResponse, ParseError> {
+    let batch = BatchRequest::new(methods);
+    let client = reqwest::blocking::Client::new();
+    batch.send(&client, url)
+}
+
+/// One entry of a JSON-RPC 2.0 batch response.
+#[derive(Debug, serde::Deserialize)]
+struct BatchResponseItem {
+    id: usize,
+    #[serde(default)]
+    result: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+/// The per-method results of a batch, demultiplexed back into the order the
+/// methods were submitted in. A failed sub-call surfaces as an `Err` for
+/// that slot without affecting the others.
+pub struct BatchResponse {
+    results: Vec<Result<serde_json::Value, serde_json::Value>>,
+}
+
+impl BatchResponse {
+    /// Parses a raw JSON-RPC batch response array and demultiplexes each
+    /// item back to the method that produced it by `id`.
+    pub fn parse(batch_len: usize, raw: serde_json::Value) -> Result<Self, serde_json::Value> {
+        let items: Vec<BatchResponseItem> = serde_json::from_value(raw)
+            .map_err(|e| serde_json::Value::String(e.to_string()))?;
+        let mut results: Vec<Option<Result<serde_json::Value, serde_json::Value>>> =
+            (0..batch_len).map(|_| None).collect();
+        for item in items {
+            let result = match (item.result, item.error) {
+                (Some(value), _) => Ok(value),
+                (None, Some(error)) => Err(error),
+                (None, None) => Err(serde_json::Value::String(
+                    "missing both result and error".into(),
+                )),
+            };
+            if let Some(slot) = results.get_mut(item.id) {
+                *slot = Some(result);
+            }
+        }
+        let results = results
+            .into_iter()
+            .map(|slot| {
+                slot.unwrap_or_else(|| {
+                    Err(serde_json::Value::String("missing response for id".into()))
+                })
+            })
+            .collect();
+        Ok(Self { results })
+    }
+
+    pub fn results(&self) -> &[Result<serde_json::Value, serde_json::Value>] {
+        &self.results
+    }
+
+    pub fn into_results(self) -> Vec<Result<serde_json::Value, serde_json::Value>> {
+        self.results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_demuxes_out_of_order_responses_by_id() {
+        let raw = serde_json::json!([
+            {"jsonrpc": "2.0", "id": 1, "result": "second"},
+            {"jsonrpc": "2.0", "id": 0, "result": "first"},
+        ]);
+        let response = BatchResponse::parse(2, raw).unwrap();
+        assert_eq!(response.results(), &[Ok("first".into()), Ok("second".into())]);
+    }
+
+    #[test]
+    fn parse_keeps_one_failed_sub_call_from_affecting_others() {
+        let raw = serde_json::json!([
+            {"jsonrpc": "2.0", "id": 0, "result": "ok"},
+            {"jsonrpc": "2.0", "id": 1, "error": {"code": -32000, "message": "boom"}},
+        ]);
+        let response = BatchResponse::parse(2, raw).unwrap().into_results();
+        assert_eq!(response[0], Ok(serde_json::Value::String("ok".into())));
+        assert!(response[1].is_err());
+    }
+
+    #[test]
+    fn parse_fills_missing_ids_with_an_error() {
+        let raw = serde_json::json!([{"jsonrpc": "2.0", "id": 0, "result": "ok"}]);
+        let response = BatchResponse::parse(2, raw).unwrap().into_results();
+        assert!(response[0].is_ok());
+        assert!(response[1].is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::cli::erc20::{wrap_error, ParseError};
+use crate::cli::solidity::read_abi;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+/// Generates the Rust source of a typed client for every function in
+/// `abi_path`, as a struct named `contract_name`. Each generated method
+/// takes its arguments as strings, reuses `solidity::parse_arg` and
+/// `solidity::find_function` to ABI-encode them, and returns a ready-to-send
+/// `EthMethod::Call`. Overloaded functions are disambiguated by appending
+/// their index among same-named overloads to the generated method name.
+pub fn generate(abi_path: String, contract_name: &str) -> Result<String, ParseError> {
+    let abi = read_abi(abi_path.clone())?;
+
+    let mut overload_counts: HashMap<&str, usize> = HashMap::new();
+    for function in abi.functions() {
+        *overload_counts.entry(function.name.as_str()).or_insert(0) += 1;
+    }
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+
+    let mut source = String::new();
+    writeln!(source, "// @generated by aurora-cli-rs abigen. Do not edit by hand.").unwrap();
+    writeln!(source, "pub struct {contract_name} {{").unwrap();
+    writeln!(source, "    pub address: aurora_engine_types::types::Address,").unwrap();
+    writeln!(source, "    pub abi_path: String,").unwrap();
+    writeln!(source, "}}").unwrap();
+    writeln!(source, "impl {contract_name} {{").unwrap();
+    for function in abi.functions() {
+        let method_name = if overload_counts[function.name.as_str()] > 1 {
+            let index = seen.entry(function.name.as_str()).or_insert(0);
+            let name = format!("{}_{}", function.name, index);
+            *index += 1;
+            name
+        } else {
+            function.name.clone()
+        };
+        write_function(&mut source, function, &method_name);
+    }
+    writeln!(source, "}}").unwrap();
+
+    Ok(source)
+}
+
+/// Rust keywords that can be escaped as a raw identifier (`r#type`).
+const RAW_ESCAPABLE_IDENTS: &[&str] = &[
+    "as", "break", "const", "continue", "else", "enum", "extern", "false", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+/// Keywords that Rust forbids even as a raw identifier (`r#self` etc. do
+/// not parse), so these need a plain rename instead of the `r#` escape.
+const RAW_INCOMPATIBLE_IDENTS: &[&str] = &["self", "Self", "super", "crate", "_"];
+
+/// Picks a valid Rust identifier for an ABI parameter: a synthesized
+/// `arg{index}` when the ABI didn't name it (legal in Solidity, e.g.
+/// `function foo(uint256, address)`), a trailing-underscore rename for
+/// keywords that can't be raw identifiers, and a raw identifier for every
+/// other keyword.
+fn param_ident(name: &str, index: usize) -> String {
+    if name.is_empty() {
+        return format!("arg{index}");
+    }
+    if RAW_INCOMPATIBLE_IDENTS.contains(&name) {
+        format!("{name}_")
+    } else if RAW_ESCAPABLE_IDENTS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn write_function(source: &mut String, function: &ethabi::Function, method_name: &str) {
+    let idents = function
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| param_ident(&input.name, i))
+        .collect::<Vec<_>>();
+    let params = idents
+        .iter()
+        .map(|ident| format!("{ident}: &str"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let signature = function.signature();
+
+    writeln!(
+        source,
+        "    pub fn {method_name}(&self, {params}) -> Result<crate::eth_method::EthMethod, crate::cli::erc20::ParseError> {{"
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "        let abi = crate::cli::solidity::read_abi(self.abi_path.clone())?;"
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "        let function = crate::cli::solidity::find_function(&abi, \"{signature}\")?;"
+    )
+    .unwrap();
+    writeln!(source, "        let tokens = vec![").unwrap();
+    for (i, ident) in idents.iter().enumerate() {
+        writeln!(
+            source,
+            "            crate::cli::solidity::parse_arg({ident}, &function.inputs[{i}].kind)?,",
+        )
+        .unwrap();
+    }
+    writeln!(source, "        ];").unwrap();
+    writeln!(
+        source,
+        "        let data = function.encode_input(&tokens).map_err(crate::cli::erc20::wrap_error)?;"
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "        Ok(crate::eth_method::EthMethod::Call(crate::eth_method::EthCall {{ from: None, to: Some(self.address), data: Some(data) }}, crate::eth_method::BlockSpecifier::Latest))"
+    )
+    .unwrap();
+    writeln!(source, "    }}").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn param_ident_synthesizes_names_for_unnamed_params() {
+        assert_eq!(param_ident("", 0), "arg0");
+        assert_eq!(param_ident("", 1), "arg1");
+    }
+
+    #[test]
+    fn param_ident_escapes_raw_escapable_keywords() {
+        assert_eq!(param_ident("type", 0), "r#type");
+    }
+
+    #[test]
+    fn param_ident_renames_raw_incompatible_keywords() {
+        assert_eq!(param_ident("self", 0), "self_");
+    }
+
+    #[test]
+    fn param_ident_keeps_ordinary_names_unchanged() {
+        assert_eq!(param_ident("amount", 0), "amount");
+    }
+
+    #[test]
+    fn generate_disambiguates_overloaded_function_names() {
+        let abi_json = r#"[
+            {"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"}],"outputs":[],"stateMutability":"nonpayable"},
+            {"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"}
+        ]"#;
+        let path = std::env::temp_dir().join(format!(
+            "aurora_cli_abigen_test_overloads_{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, abi_json).unwrap();
+        let source = generate(path.to_string_lossy().into_owned(), "TestContract").unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(source.contains("pub fn transfer_0("));
+        assert!(source.contains("pub fn transfer_1("));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::cli::erc20::{wrap_error, ParseError};
-use aurora_engine_types::{types::Address, U256};
+use aurora_engine_types::{types::Address, H256, U256};
 use clap::Subcommand;
 
 #[derive(Subcommand)]
@@ -27,6 +27,31 @@ pub enum Solidity {
         #[clap(short, long)]
         stdin_arg: Option<bool>,
     },
+    /// Decodes the hex-encoded return data of a call into JSON using the
+    /// function's `outputs` types.
+    Decode {
+        #[clap(short, long)]
+        abi_path: String,
+        #[clap(short, long)]
+        method_name: String,
+        #[clap(short, long)]
+        arg: Option<String>,
+        #[clap(short, long)]
+        stdin_arg: Option<bool>,
+    },
+    /// Decodes an `eth_getLogs` entry into JSON by matching the first topic
+    /// against each event's signature hash and decoding the remaining
+    /// indexed and data fields.
+    DecodeLog {
+        #[clap(short, long)]
+        abi_path: String,
+        #[clap(short, long)]
+        topics: String,
+        #[clap(short, long)]
+        arg: Option<String>,
+        #[clap(short, long)]
+        stdin_arg: Option<bool>,
+    },
 }
 
 impl Solidity {
@@ -39,7 +64,7 @@ impl Solidity {
                 stdin_arg,
             } => {
                 let abi = read_abi(abi_path)?;
-                let function = abi.function(&method_name).map_err(wrap_error)?;
+                let function = find_function(&abi, &method_name)?;
                 if function.inputs.len() != 1 {
                     return Err(wrap_error("Function must take only one argument"));
                 }
@@ -57,7 +82,7 @@ impl Solidity {
                 stdin_arg,
             } => {
                 let abi = read_abi(abi_path)?;
-                let function = abi.function(&method_name).map_err(wrap_error)?;
+                let function = find_function(&abi, &method_name)?;
                 let arg: serde_json::Value =
                     serde_json::from_str(&read_arg(arg, stdin_arg)).map_err(wrap_error)?;
                 let vars_map = arg
@@ -75,11 +100,91 @@ impl Solidity {
                 let bytes = function.encode_input(&tokens).map_err(wrap_error)?;
                 Ok(bytes.to_vec())
             }
+            Self::Decode { .. } | Self::DecodeLog { .. } => {
+                Err(wrap_error("Decode does not produce call input"))
+            }
+        }
+    }
+
+    pub fn abi_decode(self) -> Result<serde_json::Value, ParseError> {
+        match self {
+            Self::Decode {
+                abi_path,
+                method_name,
+                arg,
+                stdin_arg,
+            } => {
+                let abi = read_abi(abi_path)?;
+                let function = find_function(&abi, &method_name)?;
+                let data = read_arg(arg, stdin_arg);
+                let bytes =
+                    hex::decode(data.trim().trim_start_matches("0x")).map_err(wrap_error)?;
+                let tokens = function.decode_output(&bytes).map_err(wrap_error)?;
+                Ok(serde_json::Value::Array(tokens.iter().map(token_to_json).collect()))
+            }
+            Self::UnaryCall { .. } | Self::CallArgsByName { .. } | Self::DecodeLog { .. } => {
+                Err(wrap_error("Expected the Decode subcommand"))
+            }
+        }
+    }
+
+    pub fn decode_log(self) -> Result<serde_json::Value, ParseError> {
+        match self {
+            Self::DecodeLog {
+                abi_path,
+                topics,
+                arg,
+                stdin_arg,
+            } => {
+                let abi = read_abi(abi_path)?;
+                let topics = topics
+                    .split(',')
+                    .map(|topic| {
+                        let bytes = hex::decode(topic.trim().trim_start_matches("0x"))
+                            .map_err(wrap_error)?;
+                        if bytes.len() != 32 {
+                            return Err(wrap_error("Topic must be 32 bytes"));
+                        }
+                        Ok(H256::from_slice(&bytes))
+                    })
+                    .collect::<Result<Vec<H256>, ParseError>>()?;
+                let event_signature = *topics.first().ok_or_else(|| wrap_error("Missing event topic"))?;
+                let event = abi
+                    .events()
+                    .find(|event| event.signature() == event_signature)
+                    .ok_or_else(|| wrap_error("No event matches topic"))?;
+                let data = read_arg(arg, stdin_arg);
+                let data_bytes =
+                    hex::decode(data.trim().trim_start_matches("0x")).map_err(wrap_error)?;
+                let log = event
+                    .parse_log(ethabi::RawLog {
+                        topics,
+                        data: data_bytes,
+                    })
+                    .map_err(wrap_error)?;
+                let fields = log
+                    .params
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, param)| {
+                        let key = if param.name.is_empty() {
+                            format!("field{i}")
+                        } else {
+                            param.name
+                        };
+                        (key, token_to_json(&param.value))
+                    })
+                    .collect();
+                Ok(serde_json::Value::Object(fields))
+            }
+            Self::UnaryCall { .. } | Self::CallArgsByName { .. } | Self::Decode { .. } => {
+                Err(wrap_error("Expected the DecodeLog subcommand"))
+            }
         }
     }
 }
 
-fn read_abi(abi_path: String) -> Result<ethabi::Contract, ParseError> {
+pub(crate) fn read_abi(abi_path: String) -> Result<ethabi::Contract, ParseError> {
     let reader = std::fs::File::open(abi_path).map_err(wrap_error)?;
     ethabi::Contract::load(reader).map_err(wrap_error)
 }
@@ -98,7 +203,72 @@ fn read_arg(arg: Option<String>, stdin_arg: Option<bool>) -> String {
     }
 }
 
-fn parse_arg(arg: &str, kind: &ethabi::ParamType) -> Result<ethabi::Token, ParseError> {
+/// Looks up a function on `abi` by plain name, canonical signature
+/// (`transfer(address,uint256)`), or 4-byte selector (`0xa9059cbb`), so that
+/// overloaded functions can be resolved unambiguously.
+pub(crate) fn find_function<'a>(
+    abi: &'a ethabi::Contract,
+    method_name: &str,
+) -> Result<&'a ethabi::Function, ParseError> {
+    if let Some(selector_hex) = method_name.strip_prefix("0x") {
+        let selector = hex::decode(selector_hex).map_err(wrap_error)?;
+        let selector: [u8; 4] = selector
+            .as_slice()
+            .try_into()
+            .map_err(|_| wrap_error("Selector must be 4 bytes"))?;
+        return abi
+            .functions()
+            .find(|f| f.short_signature() == selector)
+            .ok_or_else(|| wrap_error("No function matches selector"));
+    }
+
+    if let Some(paren_idx) = method_name.find('(') {
+        let name = &method_name[..paren_idx];
+        let params_str = method_name[paren_idx + 1..].trim_end();
+        let params_str = params_str.strip_suffix(')').unwrap_or(params_str).trim();
+        let param_kinds = if params_str.is_empty() {
+            Vec::new()
+        } else {
+            split_top_level_params(params_str)
+                .into_iter()
+                .map(|p| ethabi::param_type::Reader::read(p.trim()).map_err(wrap_error))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        return abi
+            .functions_by_name(name)
+            .map_err(wrap_error)?
+            .iter()
+            .find(|f| f.inputs.iter().map(|i| &i.kind).eq(param_kinds.iter()))
+            .ok_or_else(|| wrap_error("No overload matches signature"));
+    }
+
+    abi.function(method_name).map_err(wrap_error)
+}
+
+/// Splits a signature's parameter-type list on `,` at parenthesis depth 0,
+/// so a tuple/struct parameter's own commas (e.g. in
+/// `transfer((address,uint256),bytes)`) aren't mistaken for separators
+/// between top-level parameters.
+fn split_top_level_params(params_str: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0usize;
+    for (i, c) in params_str.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&params_str[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&params_str[start..]);
+    parts
+}
+
+pub(crate) fn parse_arg(arg: &str, kind: &ethabi::ParamType) -> Result<ethabi::Token, ParseError> {
     match kind {
         ethabi::ParamType::Address => {
             let addr = Address::decode(arg).map_err(wrap_error)?;
@@ -108,12 +278,12 @@ fn parse_arg(arg: &str, kind: &ethabi::ParamType) -> Result<ethabi::Token, Parse
             let bytes = hex::decode(arg).map_err(wrap_error)?;
             Ok(ethabi::Token::Bytes(bytes))
         }
-        ethabi::ParamType::Int(_) => {
-            let value = U256::from_dec_str(arg).map_err(wrap_error)?;
+        ethabi::ParamType::Int(bits) => {
+            let value = parse_int(arg, *bits)?;
             Ok(ethabi::Token::Int(value))
         }
-        ethabi::ParamType::Uint(_) => {
-            let value = U256::from_dec_str(arg).map_err(wrap_error)?;
+        ethabi::ParamType::Uint(bits) => {
+            let value = parse_uint(arg, *bits)?;
             Ok(ethabi::Token::Uint(value))
         }
         ethabi::ParamType::Bool => match arg.to_lowercase().as_str() {
@@ -162,6 +332,88 @@ fn parse_arg(arg: &str, kind: &ethabi::ParamType) -> Result<ethabi::Token, Parse
     }
 }
 
+/// Parses the decimal or `0x`-prefixed hex magnitude of an integer literal.
+fn parse_uint_magnitude(arg: &str) -> Result<U256, ParseError> {
+    match arg.strip_prefix("0x").or_else(|| arg.strip_prefix("0X")) {
+        Some(hex) => U256::from_str_radix(hex, 16).map_err(wrap_error),
+        None => U256::from_dec_str(arg).map_err(wrap_error),
+    }
+}
+
+/// Parses a Solidity `uintN` argument, accepting decimal or `0x` hex input
+/// and rejecting values that do not fit in `bits`.
+fn parse_uint(arg: &str, bits: usize) -> Result<U256, ParseError> {
+    if arg.starts_with('-') {
+        return Err(wrap_error("Uint argument cannot be negative"));
+    }
+    let value = parse_uint_magnitude(arg)?;
+    if bits < 256 && value >= (U256::one() << bits) {
+        return Err(wrap_error("Uint argument out of range"));
+    }
+    Ok(value)
+}
+
+/// Parses a Solidity `intN` argument, accepting a leading `-` and decimal or
+/// `0x` hex magnitudes, and encodes the result as two's-complement over 256
+/// bits.
+fn parse_int(arg: &str, bits: usize) -> Result<U256, ParseError> {
+    let max_magnitude = if bits < 256 {
+        U256::one() << (bits - 1)
+    } else {
+        U256::one() << 255
+    };
+    match arg.strip_prefix('-') {
+        Some(magnitude_str) => {
+            let magnitude = parse_uint_magnitude(magnitude_str)?;
+            if magnitude > max_magnitude {
+                return Err(wrap_error("Int argument out of range"));
+            }
+            if magnitude.is_zero() {
+                return Ok(U256::zero());
+            }
+            Ok(U256::MAX - magnitude + U256::one())
+        }
+        None => {
+            let value = parse_uint_magnitude(arg)?;
+            if value >= max_magnitude {
+                return Err(wrap_error("Int argument out of range"));
+            }
+            Ok(value)
+        }
+    }
+}
+
+/// Renders a `Token::Int`'s two's-complement `U256` (as produced by
+/// `parse_int`) back to a signed decimal string: the sign bit (bit 255)
+/// marks negative values, whose magnitude is `U256::MAX - value + 1`.
+fn int_to_decimal_string(value: &U256) -> String {
+    if value.bit(255) {
+        let magnitude = U256::MAX - value + U256::one();
+        format!("-{magnitude}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders a decoded `ethabi::Token` as JSON, using the same type mapping as
+/// `parse_arg` but in reverse: addresses as `0x…`, bytes as hex, ints/uints
+/// as decimal strings, and arrays/tuples as nested JSON arrays.
+fn token_to_json(token: &ethabi::Token) -> serde_json::Value {
+    match token {
+        ethabi::Token::Address(addr) => serde_json::Value::String(format!("0x{}", hex::encode(addr))),
+        ethabi::Token::FixedBytes(bytes) | ethabi::Token::Bytes(bytes) => {
+            serde_json::Value::String(format!("0x{}", hex::encode(bytes)))
+        }
+        ethabi::Token::Int(value) => serde_json::Value::String(int_to_decimal_string(value)),
+        ethabi::Token::Uint(value) => serde_json::Value::String(value.to_string()),
+        ethabi::Token::Bool(b) => serde_json::Value::Bool(*b),
+        ethabi::Token::String(s) => serde_json::Value::String(s.clone()),
+        ethabi::Token::FixedArray(tokens) | ethabi::Token::Array(tokens) | ethabi::Token::Tuple(tokens) => {
+            serde_json::Value::Array(tokens.iter().map(token_to_json).collect())
+        }
+    }
+}
+
 fn parse_array(
     value: serde_json::Value,
     arr_kind: &ethabi::ParamType,
@@ -178,3 +430,94 @@ fn parse_array(
         _ => Err(wrap_error("Expected Array")),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_int_round_trips_negative_values() {
+        let encoded = parse_int("-1", 256).unwrap();
+        assert_eq!(encoded, U256::MAX);
+        assert_eq!(int_to_decimal_string(&encoded), "-1");
+
+        let encoded = parse_int("-0x80", 8).unwrap();
+        assert_eq!(int_to_decimal_string(&encoded), "-128");
+    }
+
+    #[test]
+    fn parse_int_round_trips_positive_values() {
+        let encoded = parse_int("127", 8).unwrap();
+        assert_eq!(int_to_decimal_string(&encoded), "127");
+
+        let encoded = parse_int("0x7f", 8).unwrap();
+        assert_eq!(int_to_decimal_string(&encoded), "127");
+    }
+
+    #[test]
+    fn parse_int_rejects_out_of_range_magnitudes() {
+        assert!(parse_int("-129", 8).is_err());
+        assert!(parse_int("128", 8).is_err());
+    }
+
+    #[test]
+    fn parse_uint_accepts_hex_and_rejects_negative() {
+        assert_eq!(parse_uint("0xff", 8).unwrap(), U256::from(255));
+        assert_eq!(parse_uint("0XFF", 8).unwrap(), U256::from(255));
+        assert!(parse_uint("-1", 8).is_err());
+        assert!(parse_uint("256", 8).is_err());
+    }
+
+    #[test]
+    fn split_top_level_params_keeps_tuple_params_intact() {
+        let parts = split_top_level_params("(address,uint256),bytes");
+        assert_eq!(parts, vec!["(address,uint256)", "bytes"]);
+    }
+
+    #[test]
+    fn split_top_level_params_handles_single_param() {
+        let parts = split_top_level_params("address");
+        assert_eq!(parts, vec!["address"]);
+    }
+
+    #[test]
+    fn decode_log_decodes_indexed_int_and_unnamed_fields() {
+        let abi_json = r#"[{
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                {"name": "value", "type": "int256", "indexed": true},
+                {"name": "", "type": "uint256", "indexed": false}
+            ]
+        }]"#;
+        let path = std::env::temp_dir().join(format!(
+            "aurora_cli_decode_log_test_{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, abi_json).unwrap();
+
+        let abi_path = path.to_string_lossy().into_owned();
+        let abi = read_abi(abi_path.clone()).unwrap();
+        let event = abi.events().next().unwrap();
+        let event_topic = format!("0x{}", hex::encode(event.signature()));
+
+        let mut indexed_bytes = [0u8; 32];
+        parse_int("-42", 256).unwrap().to_big_endian(&mut indexed_bytes);
+        let indexed_topic = format!("0x{}", hex::encode(indexed_bytes));
+
+        let data = ethabi::encode(&[ethabi::Token::Uint(U256::from(7))]);
+
+        let solidity = Solidity::DecodeLog {
+            abi_path,
+            topics: format!("{event_topic},{indexed_topic}"),
+            arg: Some(format!("0x{}", hex::encode(data))),
+            stdin_arg: None,
+        };
+        let decoded = solidity.decode_log().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(decoded["value"], serde_json::Value::String("-42".into()));
+        assert_eq!(decoded["field1"], serde_json::Value::String("7".into()));
+    }
+}
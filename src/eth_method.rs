@@ -3,30 +3,41 @@ use aurora_engine_types::{types::Address, H256};
 
 pub enum EthMethod {
     GetChainId,
-    GetTransactionCount(Address),
+    GetTransactionCount(Address, BlockSpecifier),
     GetTransactionReceipt(H256),
     DebugTraceTransaction(H256),
     SendRawTransaction(Box<EthTransactionKind>),
-    Call(EthCall),
+    Call(EthCall, BlockSpecifier),
+    GetLogs(LogFilter),
+    GetBalance(Address, BlockSpecifier),
+    GetStorageAt(Address, H256, BlockSpecifier),
+    BlockNumber,
 }
 
 impl EthMethod {
     pub fn name(&self) -> &'static str {
         match &self {
             Self::GetChainId => "net_version",
-            Self::GetTransactionCount(_) => "eth_getTransactionCount",
+            Self::GetTransactionCount(..) => "eth_getTransactionCount",
             Self::GetTransactionReceipt(_) => "eth_getTransactionReceipt",
             Self::DebugTraceTransaction(_) => "debug_traceTransaction",
             Self::SendRawTransaction(_) => "eth_sendRawTransaction",
-            Self::Call(_) => "eth_call",
+            Self::Call(..) => "eth_call",
+            Self::GetLogs(_) => "eth_getLogs",
+            Self::GetBalance(..) => "eth_getBalance",
+            Self::GetStorageAt(..) => "eth_getStorageAt",
+            Self::BlockNumber => "eth_blockNumber",
         }
     }
 
     pub fn create_params(&self) -> Vec<serde_json::Value> {
         match &self {
-            Self::GetChainId => Vec::new(),
-            Self::GetTransactionCount(address) => {
-                vec![serde_json::Value::String(format!("0x{}", address.encode()))]
+            Self::GetChainId | Self::BlockNumber => Vec::new(),
+            Self::GetTransactionCount(address, block) => {
+                vec![
+                    serde_json::Value::String(format!("0x{}", address.encode())),
+                    block.to_json(),
+                ]
             }
             Self::GetTransactionReceipt(tx_hash) => {
                 vec![serde_json::Value::String(format!("0x{}", hex::encode(tx_hash)))]
@@ -38,11 +49,52 @@ impl EthMethod {
                 let tx_bytes: Vec<u8> = tx.as_ref().into();
                 vec![serde_json::Value::String(format!("0x{}", hex::encode(tx_bytes)))]
             }
-            Self::Call(args) => vec![args.to_json()],
+            Self::Call(args, block) => vec![args.to_json(), block.to_json()],
+            Self::GetLogs(filter) => vec![filter.to_json()],
+            Self::GetBalance(address, block) => {
+                vec![
+                    serde_json::Value::String(format!("0x{}", address.encode())),
+                    block.to_json(),
+                ]
+            }
+            Self::GetStorageAt(address, slot, block) => {
+                vec![
+                    serde_json::Value::String(format!("0x{}", address.encode())),
+                    serde_json::Value::String(format!("0x{}", hex::encode(slot))),
+                    block.to_json(),
+                ]
+            }
+        }
+    }
+}
+
+/// A block parameter accepted by state-reading eth methods: one of the
+/// named tags `latest`/`pending`/`earliest`, or a concrete height.
+#[derive(Debug, Clone, Copy)]
+pub enum BlockSpecifier {
+    Latest,
+    Pending,
+    Earliest,
+    Number(u64),
+}
+
+impl BlockSpecifier {
+    pub fn to_json(self) -> serde_json::Value {
+        match self {
+            Self::Latest => serde_json::Value::String("latest".into()),
+            Self::Pending => serde_json::Value::String("pending".into()),
+            Self::Earliest => serde_json::Value::String("earliest".into()),
+            Self::Number(height) => serde_json::Value::String(format!("0x{height:x}")),
         }
     }
 }
 
+impl Default for BlockSpecifier {
+    fn default() -> Self {
+        Self::Latest
+    }
+}
+
 #[derive(Debug)]
 pub struct EthCall {
     pub from: Option<Address>,
@@ -69,3 +121,136 @@ impl EthCall {
         serde_json::Value::Object(obj)
     }
 }
+
+/// Filter parameters for `eth_getLogs`: an optional contract address, an
+/// optional block range, and up to four topic filters (`None` meaning any
+/// value is accepted for that position).
+#[derive(Debug)]
+pub struct LogFilter {
+    pub address: Option<Address>,
+    pub from_block: Option<BlockSpecifier>,
+    pub to_block: Option<BlockSpecifier>,
+    pub topics: Vec<Option<H256>>,
+}
+
+impl LogFilter {
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut obj = serde_json::Map::new();
+
+        if let Some(addr) = self.address.as_ref() {
+            obj.insert("address".into(), serde_json::Value::String(format!("0x{}", addr.encode())));
+        }
+
+        if let Some(from_block) = self.from_block {
+            obj.insert("fromBlock".into(), from_block.to_json());
+        }
+
+        if let Some(to_block) = self.to_block {
+            obj.insert("toBlock".into(), to_block.to_json());
+        }
+
+        if !self.topics.is_empty() {
+            let topics = self
+                .topics
+                .iter()
+                .map(|topic| match topic {
+                    Some(topic) => serde_json::Value::String(format!("0x{}", hex::encode(topic))),
+                    None => serde_json::Value::Null,
+                })
+                .collect();
+            obj.insert("topics".into(), serde_json::Value::Array(topics));
+        }
+
+        serde_json::Value::Object(obj)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr() -> Address {
+        Address::decode("000102030405060708090a0b0c0d0e0f10111213").unwrap()
+    }
+
+    #[test]
+    fn block_specifier_renders_named_tags() {
+        assert_eq!(BlockSpecifier::Latest.to_json(), serde_json::Value::String("latest".into()));
+        assert_eq!(BlockSpecifier::Pending.to_json(), serde_json::Value::String("pending".into()));
+        assert_eq!(BlockSpecifier::Earliest.to_json(), serde_json::Value::String("earliest".into()));
+    }
+
+    #[test]
+    fn block_specifier_renders_number_as_hex() {
+        assert_eq!(
+            BlockSpecifier::Number(255).to_json(),
+            serde_json::Value::String("0xff".into())
+        );
+        assert_eq!(
+            BlockSpecifier::Number(0).to_json(),
+            serde_json::Value::String("0x0".into())
+        );
+    }
+
+    #[test]
+    fn get_balance_appends_block_specifier() {
+        let method = EthMethod::GetBalance(addr(), BlockSpecifier::Number(16));
+        assert_eq!(method.name(), "eth_getBalance");
+        let params = method.create_params();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0], serde_json::Value::String(format!("0x{}", addr().encode())));
+        assert_eq!(params[1], serde_json::Value::String("0x10".into()));
+    }
+
+    #[test]
+    fn get_storage_at_orders_address_slot_then_block() {
+        let slot = H256::zero();
+        let method = EthMethod::GetStorageAt(addr(), slot, BlockSpecifier::Latest);
+        assert_eq!(method.name(), "eth_getStorageAt");
+        let params = method.create_params();
+        assert_eq!(
+            params,
+            vec![
+                serde_json::Value::String(format!("0x{}", addr().encode())),
+                serde_json::Value::String(format!("0x{}", hex::encode(slot))),
+                serde_json::Value::String("latest".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_number_has_no_params() {
+        assert_eq!(EthMethod::BlockNumber.name(), "eth_blockNumber");
+        assert!(EthMethod::BlockNumber.create_params().is_empty());
+    }
+
+    #[test]
+    fn log_filter_renders_mixed_some_and_none_topics() {
+        let topic = H256::repeat_byte(0xab);
+        let filter = LogFilter {
+            address: Some(addr()),
+            from_block: Some(BlockSpecifier::Earliest),
+            to_block: Some(BlockSpecifier::Latest),
+            topics: vec![Some(topic), None],
+        };
+
+        let expected = serde_json::json!({
+            "address": format!("0x{}", addr().encode()),
+            "fromBlock": "earliest",
+            "toBlock": "latest",
+            "topics": [format!("0x{}", hex::encode(topic)), serde_json::Value::Null],
+        });
+        assert_eq!(filter.to_json(), expected);
+    }
+
+    #[test]
+    fn log_filter_omits_absent_fields() {
+        let filter = LogFilter {
+            address: None,
+            from_block: None,
+            to_block: None,
+            topics: Vec::new(),
+        };
+        assert_eq!(filter.to_json(), serde_json::json!({}));
+    }
+}